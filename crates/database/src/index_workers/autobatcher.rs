@@ -0,0 +1,127 @@
+//! Autobatching scheduler for the jobs returned by
+//! [`SearchFlusher::needs_backfill`](super::search_flusher::SearchFlusher::needs_backfill).
+//!
+//! `needs_backfill` returns an unordered list of independent builds, which
+//! wastes work when several of them would scan the same table and can let an
+//! urgent rebuild sit behind routine ones. This module groups compatible
+//! jobs into [`Batch`]es so a single table scan can feed multiple builds, and
+//! orders those batches by [`BuildReason`] priority, modeled on MeiliSearch's
+//! autobatcher.
+
+use crate::index_workers::{
+    index_meta::{
+        SearchIndex,
+        SearchOnDiskState,
+        SnapshotData,
+    },
+    search_flusher::IndexBuild,
+    BuildReason,
+};
+
+/// The shape of document feed a job needs. Jobs with different shapes can
+/// never share a scan, even if they target the same table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildShape {
+    /// Fed via `table_iterator` + `stream_documents_in_table`, cursor-based.
+    Backfilling,
+    /// Fed via `load_documents_in_table` over a timestamp range.
+    Partial,
+}
+
+impl BuildShape {
+    fn of<T: SearchIndex>(job: &IndexBuild<T>) -> Self {
+        match job.index_config.on_disk_state {
+            SearchOnDiskState::Backfilling(_) => BuildShape::Backfilling,
+            // `build_multipart_segment_inner` treats an unrecognized snapshot
+            // format the same as a from-scratch backfill (empty
+            // previous_segments, full `IncrementalComplete` rescan), not as a
+            // `Partial` timestamp-range fetch -- so it has to share the
+            // `Backfilling` shape here too, or a batch would feed it through
+            // `load_documents_in_table` with a cursor it doesn't have.
+            SearchOnDiskState::Backfilled(ref snapshot)
+            | SearchOnDiskState::SnapshottedAt(ref snapshot) => match snapshot.data {
+                SnapshotData::Unknown => BuildShape::Backfilling,
+                SnapshotData::MultiSegment(_) => BuildShape::Partial,
+            },
+        }
+    }
+}
+
+/// A group of jobs that are compatible enough (same table, same
+/// [`BuildShape`]) to be fed by a single `table_iterator`/
+/// `load_documents_in_table` pass.
+pub struct Batch<T: SearchIndex> {
+    pub shape: BuildShape,
+    pub jobs: Vec<IndexBuild<T>>,
+}
+
+impl<T: SearchIndex> Batch<T> {
+    /// The most urgent [`BuildReason`] among the batch's members. A batch
+    /// containing a `TooLarge` job is scheduled as if the whole batch were
+    /// `TooLarge`, so urgent work never waits behind routine work it
+    /// happened to share a table with.
+    pub fn build_reason(&self) -> BuildReason {
+        self.jobs
+            .iter()
+            .map(|job| job.build_reason)
+            .max_by_key(|reason| priority(*reason))
+            .unwrap_or(BuildReason::Backfilling)
+    }
+}
+
+// Order matters! Mirrors the priority `needs_backfill` already documents:
+// too large is the most urgent, backfilling the least.
+fn priority(reason: BuildReason) -> u8 {
+    match reason {
+        BuildReason::TooLarge => 3,
+        BuildReason::VersionMismatch => 2,
+        BuildReason::TooOld => 1,
+        BuildReason::Backfilling => 0,
+    }
+}
+
+/// A pluggable decision function for whether `job` may join `batch`. Index
+/// types that can't yet share a scan (or never will) can supply a stricter
+/// function than [`default_accept`].
+pub type AcceptFn<T> = fn(batch: &Batch<T>, job: &IndexBuild<T>) -> bool;
+
+/// Default acceptance rule: a job may join a batch iff it targets the same
+/// table as the jobs already in it. Matching [`BuildShape`] is checked by
+/// [`schedule`] itself before any `AcceptFn` is consulted.
+pub fn default_accept<T: SearchIndex>(batch: &Batch<T>, job: &IndexBuild<T>) -> bool {
+    match batch.jobs.first() {
+        None => true,
+        Some(first) => *job.index_name.table() == *first.index_name.table(),
+    }
+}
+
+/// Groups `jobs` into batches via `accept`, then orders the batches so the
+/// highest-priority `BuildReason` present in a batch runs first. Batches
+/// (and jobs within a batch) otherwise keep the order `jobs` was passed in,
+/// so scheduling is deterministic given the same input.
+///
+/// This only decides *grouping and order*; it doesn't build anything.
+/// `SearchFlusher::build_batch` builds a batch: a `Partial` batch of two or
+/// more jobs shares one `load_documents_in_table` scan, with each job
+/// filtering it by its own `fast_forward_ts`; every other batch runs each
+/// job through its own `build_multipart_segment` call, keeping its own
+/// cursor bookkeeping.
+pub fn schedule<T: SearchIndex>(jobs: Vec<IndexBuild<T>>, accept: AcceptFn<T>) -> Vec<Batch<T>> {
+    let mut batches: Vec<Batch<T>> = vec![];
+    for job in jobs {
+        let shape = BuildShape::of(&job);
+        let target = batches
+            .iter_mut()
+            .find(|batch| batch.shape == shape && accept(batch, &job));
+        match target {
+            Some(batch) => batch.jobs.push(job),
+            None => batches.push(Batch {
+                shape,
+                jobs: vec![job],
+            }),
+        }
+    }
+    // Stable sort: ties keep the discovery order from `jobs`.
+    batches.sort_by(|a, b| priority(b.build_reason()).cmp(&priority(a.build_reason())));
+    batches
+}
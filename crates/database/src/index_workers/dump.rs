@@ -0,0 +1,66 @@
+//! Export/import of a search index's on-disk state as a single
+//! self-describing archive (analogous to MeiliSearch's "dump as a task"),
+//! so an environment can be cloned or disaster-recovered without
+//! re-scanning the source table that backfilled it.
+
+use std::sync::Arc;
+
+use sync_types::Timestamp;
+
+use crate::index_workers::index_meta::SegmentStatistics;
+
+pub const DUMP_ARCHIVE_VERSION: u32 = 1;
+
+/// One segment's worth of an archive: the already-durable segment itself,
+/// plus the statistics that would otherwise have to be recomputed after
+/// re-hydrating it.
+#[derive(Debug, Clone)]
+pub struct DumpSegment<Segment, Stats> {
+    pub segment: Segment,
+    pub statistics: Stats,
+}
+
+/// A versioned, self-describing snapshot of one index's
+/// `SnapshotData::MultiSegment` parts, developer config, and snapshot
+/// timestamp, sufficient to re-hydrate the index into a `Backfilled` state
+/// without touching the source table.
+#[derive(Debug, Clone)]
+pub struct DumpArchive<DeveloperConfig, Segment, Stats> {
+    pub version: u32,
+    pub ts: Timestamp,
+    pub developer_config: DeveloperConfig,
+    pub segments: Vec<DumpSegment<Segment, Stats>>,
+}
+
+/// A handle to an exported archive, returned by
+/// `SearchFlusher::export_index` and consumed by
+/// `SearchFlusher::import_index`.
+pub struct DumpHandle<DeveloperConfig, Segment, Stats> {
+    pub archive: DumpArchive<DeveloperConfig, Segment, Stats>,
+}
+
+/// Durable storage for dump archives, so an export outlives the process
+/// that created it. Kept as its own pluggable backend (rather than going
+/// through the same `Storage` object store used for live segments) so a
+/// deployment can park dumps somewhere cheaper, like cold storage.
+pub trait DumpStore<DeveloperConfig, Segment, Stats>: Send + Sync {
+    fn save(&self, name: &str, archive: &DumpArchive<DeveloperConfig, Segment, Stats>) -> anyhow::Result<()>;
+
+    fn load(&self, name: &str) -> anyhow::Result<DumpArchive<DeveloperConfig, Segment, Stats>>;
+}
+
+pub type SharedDumpStore<DeveloperConfig, Segment, Stats> =
+    Arc<dyn DumpStore<DeveloperConfig, Segment, Stats>>;
+
+/// Combine a dump archive's segments into the `total_stats` an
+/// `IndexBuildResult` is expected to carry.
+pub fn total_stats<Stats: SegmentStatistics + Default + Clone>(
+    segments: &[DumpSegment<impl Clone, Stats>],
+) -> anyhow::Result<Stats> {
+    Ok(segments
+        .iter()
+        .map(|segment| Ok(segment.statistics.clone()))
+        .reduce(SegmentStatistics::add)
+        .transpose()?
+        .unwrap_or_default())
+}
@@ -0,0 +1,172 @@
+//! Merge policies for combining small multi-segment search index segments
+//! into fewer, larger ones.
+//!
+//! Every flusher iteration that doesn't need to ingest new documents can
+//! instead spend its budget merging existing segments together so that an
+//! index doesn't accumulate an unbounded number of small segments over time,
+//! which would otherwise slow queries and waste storage.
+
+use crate::index_workers::index_meta::SegmentStatistics;
+
+/// A group of segment indexes (positions into the index's current
+/// `previous_segments`) that a [`MergePolicy`] has decided should be merged
+/// into a single replacement segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeCandidate {
+    pub segment_indexes: Vec<usize>,
+}
+
+/// Decides which segments of a multi-segment search index should be merged
+/// together, given the statistics of every segment currently making up the
+/// index.
+///
+/// Implementations should only return candidates that are worth the cost of
+/// a merge; callers treat an empty result as "nothing to do this
+/// iteration".
+pub trait MergePolicy<Stats: SegmentStatistics>: Send + Sync {
+    fn merge_candidates(&self, segment_stats: &[Stats]) -> Vec<MergeCandidate>;
+}
+
+/// Segment statistics that can report their own on-disk footprint, needed
+/// anywhere a merge budget is expressed in bytes (e.g.
+/// `compaction_window_bytes`) rather than in document count. Kept separate
+/// from `SegmentStatistics` itself so leveling logic that only cares about
+/// document count (like [`LogMergePolicy`], which mirrors tantivy's own
+/// doc-count-based leveling) doesn't need to carry a size estimate too.
+pub trait SizedSegmentStatistics: SegmentStatistics {
+    fn size_bytes(&self) -> u64;
+}
+
+/// Default merge policy, modeled on tantivy's `LogMergePolicy`.
+///
+/// Segments are sorted by document count descending and then walked to form
+/// geometrically sized "levels": a level keeps accepting segments as long as
+/// their log-size is within `level_log_size` of the log-size of the first
+/// (largest) segment admitted to that level. Levels that accumulate at least
+/// `min_merge_segments` segments are emitted as merge candidates. Segments
+/// smaller than `min_layer_size` documents are all clamped to the same floor
+/// layer so that a long tail of tiny segments merges promptly instead of
+/// each forming its own level.
+#[derive(Debug, Clone, Copy)]
+pub struct LogMergePolicy {
+    pub min_merge_segments: usize,
+    pub level_log_size: f64,
+    pub min_layer_size: u64,
+}
+
+impl Default for LogMergePolicy {
+    fn default() -> Self {
+        Self {
+            min_merge_segments: 8,
+            level_log_size: 0.75,
+            min_layer_size: 10_000,
+        }
+    }
+}
+
+impl LogMergePolicy {
+    fn log_size(&self, num_documents: u64) -> f64 {
+        (num_documents.max(self.min_layer_size) as f64).log2()
+    }
+}
+
+impl<Stats: SegmentStatistics> MergePolicy<Stats> for LogMergePolicy {
+    fn merge_candidates(&self, segment_stats: &[Stats]) -> Vec<MergeCandidate> {
+        let mut by_size: Vec<(usize, u64)> = segment_stats
+            .iter()
+            .enumerate()
+            .map(|(i, stats)| (i, stats.num_documents()))
+            .collect();
+        by_size.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let mut candidates = vec![];
+        let mut level: Vec<usize> = vec![];
+        let mut level_log_size = None;
+        for (index, num_documents) in by_size {
+            let log_size = self.log_size(num_documents);
+            let fits_current_level = level_log_size
+                .is_some_and(|largest: f64| largest - log_size <= self.level_log_size);
+            if !fits_current_level {
+                if level.len() >= self.min_merge_segments {
+                    candidates.push(MergeCandidate {
+                        segment_indexes: std::mem::take(&mut level),
+                    });
+                } else {
+                    level.clear();
+                }
+                level_log_size = Some(log_size);
+            }
+            level.push(index);
+        }
+        if level.len() >= self.min_merge_segments {
+            candidates.push(MergeCandidate {
+                segment_indexes: level,
+            });
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct FakeStats(u64);
+
+    impl SegmentStatistics for FakeStats {
+        fn num_documents(&self) -> u64 {
+            self.0
+        }
+
+        fn add(a: anyhow::Result<Self>, b: anyhow::Result<Self>) -> anyhow::Result<Self> {
+            Ok(Self(a?.0 + b?.0))
+        }
+
+        fn log(&self) {}
+    }
+
+    fn policy() -> LogMergePolicy {
+        LogMergePolicy {
+            min_merge_segments: 3,
+            ..LogMergePolicy::default()
+        }
+    }
+
+    #[test]
+    fn small_number_of_segments_is_not_merged() {
+        let stats = vec![FakeStats(100), FakeStats(100)];
+        assert!(policy().merge_candidates(&stats).is_empty());
+    }
+
+    #[test]
+    fn similarly_sized_segments_merge_together() {
+        let stats = vec![
+            FakeStats(100_000),
+            FakeStats(110_000),
+            FakeStats(90_000),
+            FakeStats(95_000),
+        ];
+        let candidates = policy().merge_candidates(&stats);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].segment_indexes.len(), 4);
+    }
+
+    #[test]
+    fn a_single_huge_segment_is_never_a_merge_candidate_on_its_own() {
+        let mut stats = vec![FakeStats(10_000_000)];
+        stats.extend((0..3).map(|_| FakeStats(100)));
+        let candidates = policy().merge_candidates(&stats);
+        for candidate in candidates {
+            assert!(!candidate.segment_indexes.contains(&0));
+        }
+    }
+
+    #[test]
+    fn tiny_segments_below_the_floor_share_one_layer() {
+        let stats = vec![FakeStats(1), FakeStats(5), FakeStats(9), FakeStats(3)];
+        let candidates = policy().merge_candidates(&stats);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].segment_indexes.len(), 4);
+    }
+}
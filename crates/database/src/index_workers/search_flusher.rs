@@ -28,6 +28,7 @@ use common::{
 };
 use futures::{
     channel::oneshot,
+    stream,
     StreamExt,
     TryStreamExt,
 };
@@ -53,6 +54,29 @@ use crate::{
             SegmentStatistics,
             SnapshotData,
         },
+        autobatcher::{
+            self,
+            AcceptFn,
+        },
+        merge_policy::{
+            LogMergePolicy,
+            MergePolicy,
+            SizedSegmentStatistics,
+        },
+        dump::{
+            self,
+            DumpArchive,
+            DumpHandle,
+            DumpSegment,
+            SharedDumpStore,
+        },
+        streaming_compaction,
+        task_store::{
+            TaskHistory,
+            TaskQuery,
+            TaskStore,
+            TaskUid,
+        },
         BuildReason,
         MultiSegmentBackfillResult,
     },
@@ -69,9 +93,32 @@ pub struct SearchFlusher<RT: Runtime, T: SearchIndexConfigParser> {
     full_scan_threshold_kb: usize,
     // Used for constraining the part size of incremental multi segment builds
     incremental_multipart_threshold_bytes: usize,
+    // Bounds the peak local footprint of a single segment merge; see
+    // `streaming_compaction::compact_window`.
+    compaction_window_bytes: u64,
+    merge_policy: Arc<dyn MergePolicy<<T::IndexType as SearchIndex>::Statistics>>,
+    batch_accept: AcceptFn<T::IndexType>,
+    task_store: Arc<TaskStore<<T::IndexType as SearchIndex>::Statistics>>,
+    dump_store: Option<
+        SharedDumpStore<
+            <T::IndexType as SearchIndex>::DeveloperConfig,
+            <T::IndexType as SearchIndex>::Segment,
+            <T::IndexType as SearchIndex>::Statistics,
+        >,
+    >,
     _config: PhantomData<T>,
 }
 
+// Tasks older than this many entries are dropped from memory; completed
+// tasks are still retrievable via `TaskHistory` if one was configured.
+const MAX_TASKS_IN_MEMORY: usize = 1000;
+
+// How often (in rows scanned) an `IncrementalComplete` build reports live
+// progress to its `TaskStore`. `update_progress` takes a mutex lock shared
+// with every other in-flight build, so this trades some progress-reporting
+// granularity for not contending that lock on every single document.
+const PROGRESS_UPDATE_ROWS: u64 = 256;
+
 impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
     pub fn new(
         runtime: RT,
@@ -80,6 +127,11 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         index_size_soft_limit: usize,
         full_scan_threshold_kb: usize,
         incremental_multipart_threshold_bytes: usize,
+        compaction_window_bytes: u64,
+        min_merge_segments: usize,
+        level_log_size: f64,
+        min_layer_size: u64,
+        task_history: Option<Arc<dyn TaskHistory<<T::IndexType as SearchIndex>::Statistics>>>,
     ) -> Self {
         Self {
             runtime,
@@ -88,10 +140,288 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
             index_size_soft_limit,
             full_scan_threshold_kb,
             incremental_multipart_threshold_bytes,
+            compaction_window_bytes,
+            merge_policy: Arc::new(LogMergePolicy {
+                min_merge_segments,
+                level_log_size,
+                min_layer_size,
+            }),
+            batch_accept: autobatcher::default_accept,
+            task_store: Arc::new(TaskStore::new(MAX_TASKS_IN_MEMORY, task_history)),
+            dump_store: None,
             _config: PhantomData,
         }
     }
 
+    /// Configure where `export_index` persists archives. `import_index` does
+    /// not read from this store; it re-hydrates the `DumpHandle` it is
+    /// given. Required before calling `export_index`: an archive that
+    /// only lives in the returned `DumpHandle` defeats the point of exporting
+    /// it in the first place, so `export_index` errors out without one
+    /// configured rather than silently skipping durability.
+    pub fn with_dump_store(
+        mut self,
+        dump_store: SharedDumpStore<
+            <T::IndexType as SearchIndex>::DeveloperConfig,
+            <T::IndexType as SearchIndex>::Segment,
+            <T::IndexType as SearchIndex>::Statistics,
+        >,
+    ) -> Self {
+        self.dump_store = Some(dump_store);
+        self
+    }
+
+    /// Query in-flight and historical index builds, e.g. to poll backfill
+    /// progress or diagnose a stuck or repeatedly-rebuilding index.
+    pub fn query_tasks(
+        &self,
+        query: &TaskQuery,
+    ) -> Vec<crate::index_workers::task_store::Task<<T::IndexType as SearchIndex>::Statistics>>
+    {
+        self.task_store.query(query)
+    }
+
+    /// Override the acceptance rule used by [`Self::schedule_builds`]. Index
+    /// types that can't share a table scan with others yet can supply a
+    /// stricter function than [`autobatcher::default_accept`].
+    pub fn with_batch_accept(mut self, accept: AcceptFn<T::IndexType>) -> Self {
+        self.batch_accept = accept;
+        self
+    }
+
+    /// Group and prioritize the jobs returned by [`Self::needs_backfill`]:
+    /// jobs that target the same table and have the same build shape are
+    /// grouped so a single scan can feed multiple builds, and the resulting
+    /// batches are ordered so the most urgent [`BuildReason`] runs first.
+    pub fn schedule_builds(
+        &self,
+        jobs: Vec<IndexBuild<T::IndexType>>,
+    ) -> Vec<autobatcher::Batch<T::IndexType>> {
+        autobatcher::schedule(jobs, self.batch_accept)
+    }
+
+    /// Build every job in `batch`, sharing a single table scan across them
+    /// where that's possible instead of having each job run its own
+    /// `table_iterator`/`load_documents_in_table` pass.
+    ///
+    /// Only `BuildShape::Partial` batches are actually scan-shared today: all
+    /// of their jobs come from the same `needs_backfill` pass and so agree on
+    /// the scan's upper bound (`snapshot_ts`), and only differ in their own
+    /// `fast_forward_ts` lower bound, which makes a single shared
+    /// `load_documents_in_table` call safe to replay per job. `Backfilling`
+    /// jobs can each be sitting at a different cursor, and replaying a
+    /// shared scan against an arbitrary cursor risks silently skipping or
+    /// re-processing documents, so those still run through their own
+    /// `build_multipart_segment` call for now.
+    pub async fn build_batch(
+        &self,
+        batch: autobatcher::Batch<T::IndexType>,
+    ) -> Vec<anyhow::Result<IndexBuildResult<T::IndexType>>> {
+        if batch.jobs.len() < 2 || batch.shape != autobatcher::BuildShape::Partial {
+            let mut results = Vec::with_capacity(batch.jobs.len());
+            for job in &batch.jobs {
+                results.push(self.build_multipart_segment(job).await);
+            }
+            return results;
+        }
+
+        // Enqueue every job's task before the shared scan starts, so a
+        // failure in the scan itself (not just in a per-job build) still
+        // leaves a queryable failed task behind for each job, the same way a
+        // solo `build_multipart_segment` failure does.
+        let now = self.runtime.unix_timestamp();
+        let task_uids: Vec<_> = batch
+            .jobs
+            .iter()
+            .map(|job| {
+                let task_uid = self.task_store.enqueue(
+                    job.index_id,
+                    job.index_name.to_string(),
+                    job.build_reason,
+                    now,
+                );
+                self.task_store.mark_processing(task_uid, now);
+                task_uid
+            })
+            .collect();
+
+        match self.build_batch_partial(&batch.jobs, &task_uids).await {
+            Ok(results) => results,
+            Err(e) => {
+                let now = self.runtime.unix_timestamp();
+                for task_uid in &task_uids {
+                    self.task_store.mark_failed(*task_uid, e.to_string(), now);
+                }
+                batch
+                    .jobs
+                    .iter()
+                    .map(|_| Err(anyhow::anyhow!("{e}")))
+                    .collect()
+            },
+        }
+    }
+
+    async fn build_batch_partial(
+        &self,
+        jobs: &[IndexBuild<T::IndexType>],
+        task_uids: &[TaskUid],
+    ) -> anyhow::Result<Vec<anyhow::Result<IndexBuildResult<T::IndexType>>>> {
+        let table_id = *jobs[0].index_name.table();
+        let mut tx = self.database.begin(Identity::system()).await?;
+        let snapshot_ts = tx.begin_timestamp();
+
+        let mut job_last_ts = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            let snapshot = match &job.index_config.on_disk_state {
+                SearchOnDiskState::Backfilled(snapshot) | SearchOnDiskState::SnapshottedAt(snapshot) => snapshot,
+                SearchOnDiskState::Backfilling(_) => {
+                    anyhow::bail!("batched partial build given a backfilling job")
+                },
+            };
+            let last_ts = IndexWorkerMetadataModel::new(&mut tx)
+                .get_fast_forward_ts(snapshot.ts, job.index_id)
+                .await?;
+            job_last_ts.push(last_ts);
+        }
+        let min_last_ts = *job_last_ts.iter().min().context("empty batch")?;
+
+        let rate_limit_pages_per_second = jobs
+            .iter()
+            .map(|job| job.build_reason.read_max_pages_per_second())
+            .max()
+            .context("empty batch")?;
+        let row_rate_limiter = new_rate_limiter(
+            self.runtime.clone(),
+            Quota::per_second(
+                NonZeroU32::new(*DEFAULT_DOCUMENTS_PAGE_SIZE)
+                    .and_then(|val| val.checked_mul(rate_limit_pages_per_second))
+                    .context("Invalid row rate limit")?,
+            ),
+        );
+
+        // One shared scan for the whole batch; each job below replays it,
+        // keeping only the documents after its own `fast_forward_ts`.
+        let documents: Vec<_> = self
+            .database
+            .load_documents_in_table(
+                table_id,
+                TimestampRange::new((
+                    Bound::Excluded(min_last_ts),
+                    Bound::Included(*snapshot_ts),
+                ))?,
+                &row_rate_limiter,
+            )
+            .try_collect()
+            .await?;
+
+        let mut results = vec![];
+        for ((job, last_ts), &task_uid) in jobs.iter().zip(job_last_ts).zip(task_uids) {
+            let job_documents = documents
+                .iter()
+                .filter(|(ts, ..)| *ts > last_ts)
+                .cloned()
+                .collect::<Vec<_>>();
+            let previous_segments_result = match &job.index_config.on_disk_state {
+                SearchOnDiskState::Backfilled(snapshot) | SearchOnDiskState::SnapshottedAt(snapshot) => {
+                    match &snapshot.data {
+                        SnapshotData::MultiSegment(parts) => Ok(parts.clone()),
+                        SnapshotData::Unknown => {
+                            Err(anyhow::anyhow!("Unknown snapshot data in a Partial batch"))
+                        },
+                    }
+                },
+                SearchOnDiskState::Backfilling(_) => unreachable!("checked above"),
+            };
+            let developer_config = job.index_config.developer_config.clone();
+
+            // Building a segment is CPU/blocking work, so (matching
+            // `build_multipart_segment_in_dir`) it runs on its own thread
+            // rather than inline on the async executor.
+            let result: anyhow::Result<_> = async {
+                let previous_segments = previous_segments_result?;
+                let storage = self.storage.clone();
+                let runtime = self.runtime.clone();
+                let full_scan_threshold_kb = self.full_scan_threshold_kb;
+
+                let (tx, rx) = oneshot::channel();
+                self.runtime.spawn_thread(move || async move {
+                    let result: anyhow::Result<_> = async {
+                        let index_path = TempDir::new()?;
+                        let qdrant_schema = T::IndexType::new_schema(&developer_config);
+                        let mut mutable_previous_segments =
+                            T::IndexType::download_previous_segments(storage.clone(), previous_segments)
+                                .await?;
+                        let new_segment = T::IndexType::build_disk_index(
+                            &qdrant_schema,
+                            index_path.path(),
+                            stream::iter(job_documents.into_iter().map(Ok)),
+                            full_scan_threshold_kb,
+                            &mut mutable_previous_segments,
+                        )
+                        .await?;
+                        let updated_previous_segments = T::IndexType::upload_previous_segments(
+                            storage.clone(),
+                            mutable_previous_segments,
+                        )
+                        .await?;
+                        let new_segment = match new_segment {
+                            Some(new_segment) => Some(
+                                T::IndexType::upload_new_segment(&runtime, storage, new_segment).await?,
+                            ),
+                            None => None,
+                        };
+                        let new_segment_id = new_segment.as_ref().map(T::IndexType::segment_id);
+                        let new_segment_stats =
+                            new_segment.as_ref().map(T::IndexType::statistics).transpose()?;
+                        let new_and_updated_parts = if let Some(new_segment) = new_segment {
+                            updated_previous_segments
+                                .into_iter()
+                                .chain(iter::once(new_segment))
+                                .collect()
+                        } else {
+                            updated_previous_segments
+                        };
+                        let total_stats = new_and_updated_parts
+                            .iter()
+                            .map(|segment| {
+                                let segment_stats = T::IndexType::statistics(segment)?;
+                                segment_stats.log();
+                                Ok(segment_stats)
+                            })
+                            .reduce(SegmentStatistics::add)
+                            .transpose()?
+                            .unwrap_or_default();
+                        Ok((new_and_updated_parts, total_stats, new_segment_stats, new_segment_id))
+                    }
+                    .await;
+                    _ = tx.send(result);
+                });
+                let (data_parts, total_stats, new_segment_stats, new_segment_id) = rx.await??;
+
+                Ok(IndexBuildResult {
+                    snapshot_ts: *snapshot_ts,
+                    data: SnapshotData::MultiSegment(data_parts),
+                    total_stats,
+                    new_segment_stats,
+                    new_segment_id,
+                    backfill_result: None,
+                })
+            }
+            .await;
+
+            let now = self.runtime.unix_timestamp();
+            match &result {
+                Ok(build_result) => {
+                    self.task_store
+                        .mark_succeeded(task_uid, build_result.total_stats.clone(), now);
+                },
+                Err(e) => self.task_store.mark_failed(task_uid, e.to_string(), now),
+            }
+            results.push(result);
+        }
+        Ok(results)
+    }
+
     /// Compute the set of indexes that need to be backfilled.
     pub async fn needs_backfill(&self) -> anyhow::Result<(Vec<IndexBuild<T::IndexType>>, Token)> {
         let mut to_build = vec![];
@@ -170,6 +500,33 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
     pub async fn build_multipart_segment(
         &self,
         job: &IndexBuild<T::IndexType>,
+    ) -> anyhow::Result<IndexBuildResult<T::IndexType>> {
+        let now = self.runtime.unix_timestamp();
+        let task_uid = self.task_store.enqueue(
+            job.index_id,
+            job.index_name.to_string(),
+            job.build_reason,
+            now,
+        );
+        self.task_store.mark_processing(task_uid, now);
+
+        let result = self.build_multipart_segment_inner(job, task_uid).await;
+
+        let now = self.runtime.unix_timestamp();
+        match &result {
+            Ok(build_result) => {
+                self.task_store
+                    .mark_succeeded(task_uid, build_result.total_stats.clone(), now);
+            },
+            Err(e) => self.task_store.mark_failed(task_uid, e.to_string(), now),
+        }
+        result
+    }
+
+    async fn build_multipart_segment_inner(
+        &self,
+        job: &IndexBuild<T::IndexType>,
+        task_uid: TaskUid,
     ) -> anyhow::Result<IndexBuildResult<T::IndexType>> {
         let index_path = TempDir::new()?;
         let mut tx = self.database.begin(Identity::system()).await?;
@@ -226,7 +583,14 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
             updated_previous_segments,
             backfill_result,
         } = self
-            .build_multipart_segment_in_dir(job, &index_path, new_ts, build_type, previous_segments)
+            .build_multipart_segment_in_dir(
+                job,
+                &index_path,
+                new_ts,
+                build_type,
+                previous_segments,
+                task_uid,
+            )
             .await?;
 
         let new_segment = if let Some(new_segment) = new_segment {
@@ -274,6 +638,248 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         })
     }
 
+    /// Merge small segments of an already-backfilled index together,
+    /// consulting `self.merge_policy` for which segments are worth merging
+    /// this iteration. Returns `None` if the policy found nothing to do, so
+    /// callers can fall back to a normal incremental `build_multipart_segment`
+    /// pass.
+    pub async fn build_merge_segment(
+        &self,
+        job: &IndexBuild<T::IndexType>,
+    ) -> anyhow::Result<Option<IndexBuildResult<T::IndexType>>>
+    where
+        <T::IndexType as SearchIndex>::Statistics: SizedSegmentStatistics,
+        T::IndexType: streaming_compaction::SegmentMerge,
+    {
+        let (ts, previous_segments) = match job.index_config.on_disk_state {
+            SearchOnDiskState::Backfilled(ref snapshot)
+            | SearchOnDiskState::SnapshottedAt(ref snapshot) => match snapshot.data {
+                SnapshotData::MultiSegment(ref parts) => (snapshot.ts, parts.clone()),
+                SnapshotData::Unknown => return Ok(None),
+            },
+            // Backfilling indexes get new segments from `build_multipart_segment`
+            // until the backfill completes; they aren't merge candidates yet.
+            SearchOnDiskState::Backfilling(_) => return Ok(None),
+        };
+
+        let segment_stats = previous_segments
+            .iter()
+            .map(T::IndexType::statistics)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let Some(candidate) = self
+            .merge_policy
+            .merge_candidates(&segment_stats)
+            .into_iter()
+            .next()
+        else {
+            return Ok(None);
+        };
+
+        // A huge merge can itself be split across flusher iterations: only
+        // take a prefix of the candidate whose on-disk size fits under the
+        // same `incremental_multipart_threshold_bytes` bound used for
+        // incremental builds, leaving the remainder for the next call. The
+        // prefix always holds at least two segments, since a single segment
+        // has nothing to merge with and would never make progress.
+        let mut merge_indexes = vec![];
+        let mut batch_size_bytes = 0u64;
+        for index in candidate.segment_indexes {
+            let size_bytes = segment_stats[index].size_bytes();
+            if merge_indexes.len() >= 2
+                && batch_size_bytes + size_bytes > self.incremental_multipart_threshold_bytes as u64
+            {
+                break;
+            }
+            batch_size_bytes += size_bytes;
+            merge_indexes.push(index);
+        }
+
+        let mut to_merge = vec![];
+        let mut to_merge_size_bytes = vec![];
+        let mut kept = vec![];
+        for (index, segment) in previous_segments.into_iter().enumerate() {
+            if merge_indexes.contains(&index) {
+                to_merge_size_bytes.push(segment_stats[index].size_bytes());
+                to_merge.push(segment);
+            } else {
+                kept.push(segment);
+            }
+        }
+        tracing::info!(
+            "Merging {} segments for index {:?} ({:?})",
+            to_merge.len(),
+            job.index_name,
+            job.build_reason,
+        );
+
+        // `compact_window` only folds together as much of `to_merge` as fits
+        // under `compaction_window_bytes`, so the merge may only make
+        // partial progress this iteration; whatever it couldn't fit goes
+        // back into `kept` untouched and will be reconsidered by
+        // `self.merge_policy` on a future call, the same way an oversized
+        // `IncrementalComplete` backfill resumes from its cursor.
+        //
+        // Like `build_multipart_segment_in_dir`, the download+merge work is
+        // CPU/blocking, so it runs on its own thread rather than inline on
+        // the async executor.
+        let storage = self.storage.clone();
+        let runtime = self.runtime.clone();
+        let compaction_window_bytes = self.compaction_window_bytes;
+        let (tx, rx) = oneshot::channel();
+        self.runtime.spawn_thread(move || async move {
+            let result: anyhow::Result<_> = async {
+                let index_path = TempDir::new()?;
+                streaming_compaction::compact_window::<RT, T::IndexType>(
+                    &runtime,
+                    storage,
+                    index_path.path(),
+                    to_merge,
+                    to_merge_size_bytes,
+                    compaction_window_bytes,
+                )
+                .await
+            }
+            .await;
+            _ = tx.send(result);
+        });
+        let streaming_compaction::CompactionResult { merged, leftover } = rx.await??;
+        let Some(merged_segment) = merged else {
+            return Ok(None);
+        };
+        let new_segment_id = Some(T::IndexType::segment_id(&merged_segment));
+        let new_segment_stats = Some(T::IndexType::statistics(&merged_segment)?);
+
+        let mut new_and_updated_parts = kept;
+        new_and_updated_parts.extend(leftover);
+        new_and_updated_parts.push(merged_segment);
+        let total_stats = new_and_updated_parts
+            .iter()
+            .map(|segment| {
+                let segment_stats = T::IndexType::statistics(segment)?;
+                segment_stats.log();
+                Ok(segment_stats)
+            })
+            .reduce(SegmentStatistics::add)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Some(IndexBuildResult {
+            snapshot_ts: ts,
+            data: SnapshotData::MultiSegment(new_and_updated_parts),
+            total_stats,
+            new_segment_stats,
+            new_segment_id,
+            backfill_result: None,
+        }))
+    }
+
+    /// Export an already-backfilled index's current `SnapshotData::MultiSegment`
+    /// parts, developer config, and snapshot timestamp into a single
+    /// self-describing archive, for fast environment cloning or disaster
+    /// recovery without re-scanning the source table.
+    pub async fn export_index(
+        &self,
+        job: &IndexBuild<T::IndexType>,
+    ) -> anyhow::Result<
+        DumpHandle<
+            <T::IndexType as SearchIndex>::DeveloperConfig,
+            <T::IndexType as SearchIndex>::Segment,
+            <T::IndexType as SearchIndex>::Statistics,
+        >,
+    > {
+        let (ts, parts) = match job.index_config.on_disk_state {
+            SearchOnDiskState::Backfilled(ref snapshot) | SearchOnDiskState::SnapshottedAt(ref snapshot) => {
+                match snapshot.data {
+                    SnapshotData::MultiSegment(ref parts) => (snapshot.ts, parts.clone()),
+                    SnapshotData::Unknown => {
+                        anyhow::bail!("cannot export {:?}: unrecognized snapshot format", job.index_name)
+                    },
+                }
+            },
+            SearchOnDiskState::Backfilling(_) => {
+                anyhow::bail!("cannot export {:?}: backfill is still in progress", job.index_name)
+            },
+        };
+
+        let dump_store = self.dump_store.as_ref().context(
+            "cannot export an index archive without a dump_store configured; call \
+             with_dump_store on this SearchFlusher first",
+        )?;
+
+        let segments = parts
+            .into_iter()
+            .map(|segment| {
+                let statistics = T::IndexType::statistics(&segment)?;
+                Ok(DumpSegment { segment, statistics })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let archive = DumpArchive {
+            version: dump::DUMP_ARCHIVE_VERSION,
+            ts,
+            developer_config: job.index_config.developer_config.clone(),
+            segments,
+        };
+        dump_store.save(&format!("{:?}", job.index_name), &archive)?;
+        Ok(DumpHandle { archive })
+    }
+
+    /// Re-hydrate a previously exported archive into a `Backfilled` index,
+    /// without re-scanning the source table. The archive's segments are
+    /// already durable, but are still run through `upload_previous_segments`
+    /// so any storage-backend bookkeeping that path performs (e.g. refcounting)
+    /// stays correct.
+    pub async fn import_index(
+        &self,
+        job: &IndexBuild<T::IndexType>,
+        handle: DumpHandle<
+            <T::IndexType as SearchIndex>::DeveloperConfig,
+            <T::IndexType as SearchIndex>::Segment,
+            <T::IndexType as SearchIndex>::Statistics,
+        >,
+    ) -> anyhow::Result<IndexBuildResult<T::IndexType>> {
+        let archive = handle.archive;
+        anyhow::ensure!(
+            archive.version == dump::DUMP_ARCHIVE_VERSION,
+            "unsupported dump archive version {} for {:?} (expected {})",
+            archive.version,
+            job.index_name,
+            dump::DUMP_ARCHIVE_VERSION,
+        );
+
+        // Compute total_stats from the archive's own per-segment statistics
+        // rather than recomputing them after upload: that's the whole point
+        // of persisting them in the archive instead of just the raw
+        // segments.
+        let total_stats = dump::total_stats(&archive.segments)?;
+        let segments: Vec<_> = archive.segments.into_iter().map(|s| s.segment).collect();
+        let probe_snapshot = SearchSnapshot {
+            ts: archive.ts,
+            data: SnapshotData::MultiSegment(segments.clone()),
+        };
+        anyhow::ensure!(
+            T::IndexType::is_version_current(&probe_snapshot),
+            "dump archive for {:?} is for an outdated index format; rebuild from scratch instead",
+            job.index_name,
+        );
+
+        let uploaded = T::IndexType::upload_previous_segments(self.storage.clone(), segments).await?;
+
+        tracing::info!(
+            "Imported dump archive for {:?} ({} segments)",
+            job.index_name,
+            uploaded.len(),
+        );
+
+        Ok(IndexBuildResult {
+            snapshot_ts: archive.ts,
+            data: SnapshotData::MultiSegment(uploaded),
+            total_stats,
+            new_segment_stats: None,
+            new_segment_id: None,
+            backfill_result: None,
+        })
+    }
+
     async fn build_multipart_segment_in_dir(
         &self,
         job: &IndexBuild<T::IndexType>,
@@ -281,6 +887,7 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         snapshot_ts: RepeatableTimestamp,
         build_type: MultipartBuildType,
         previous_segments: Vec<<T::IndexType as SearchIndex>::Segment>,
+        task_uid: TaskUid,
     ) -> anyhow::Result<MultiSegmentBuildResult<T::IndexType>> {
         let database = self.database.clone();
 
@@ -294,6 +901,7 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         let by_id = job.by_id;
         let rate_limit_pages_per_second = job.build_reason.read_max_pages_per_second();
         let developer_config = job.index_config.developer_config.clone();
+        let task_store = self.task_store.clone();
         self.runtime.spawn_thread(move || async move {
             let result = Self::build_multipart_segment_on_thread(
                 rate_limit_pages_per_second,
@@ -309,6 +917,8 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
                 previous_segments,
                 full_scan_threshold_kb,
                 incremental_multipart_threshold_bytes,
+                task_store,
+                task_uid,
             )
             .await;
             _ = tx.send(result);
@@ -316,6 +926,7 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         rx.await?
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn build_multipart_segment_on_thread(
         rate_limit_pages_per_second: NonZeroU32,
         index_name: TabletIndexName,
@@ -330,6 +941,8 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
         previous_segments: Vec<<T::IndexType as SearchIndex>::Segment>,
         full_scan_threshold_kb: usize,
         incremental_multipart_threshold_bytes: usize,
+        task_store: Arc<TaskStore<<T::IndexType as SearchIndex>::Statistics>>,
+        task_uid: TaskUid,
     ) -> anyhow::Result<MultiSegmentBuildResult<T::IndexType>> {
         let row_rate_limiter = new_rate_limiter(
             runtime,
@@ -364,7 +977,7 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
                     .table_iterator(backfill_snapshot_ts, *VECTOR_INDEX_WORKER_PAGE_SIZE, None)
                     .stream_documents_in_table(*index_name.table(), by_id, cursor)
                     .boxed()
-                    .scan(0_u64, |total_size, res| {
+                    .scan((0_u64, 0_u64), |(total_size, rows_since_report), res| {
                         let updated_cursor = if let Ok((doc, _)) = &res {
                             let size = T::IndexType::estimate_document_size(&qdrant_schema, doc);
                             *total_size += size;
@@ -374,17 +987,26 @@ impl<RT: Runtime, T: SearchIndexConfigParser + 'static> SearchFlusher<RT, T> {
                         };
                         // Conditionally update cursor and proceed with iteration if
                         // we haven't exceeded incremental part size threshold.
-                        future::ready(
-                            if *total_size <= incremental_multipart_threshold_bytes as u64 {
-                                if let Some(updated_cursor) = updated_cursor {
-                                    new_cursor = Some(updated_cursor);
-                                }
-                                Some(res)
-                            } else {
-                                is_backfill_complete = false;
-                                None
-                            },
-                        )
+                        let keep_going = if *total_size <= incremental_multipart_threshold_bytes as u64 {
+                            if let Some(updated_cursor) = updated_cursor {
+                                new_cursor = Some(updated_cursor);
+                            }
+                            true
+                        } else {
+                            is_backfill_complete = false;
+                            false
+                        };
+                        // `update_progress` takes a mutex lock on a `TaskStore`
+                        // shared across every in-flight build, so only report
+                        // every `PROGRESS_UPDATE_ROWS` rows (plus on the row that
+                        // stops the scan at the part size threshold) instead of
+                        // once per document.
+                        *rows_since_report += 1;
+                        if !keep_going || *rows_since_report >= PROGRESS_UPDATE_ROWS {
+                            *rows_since_report = 0;
+                            task_store.update_progress(task_uid, *total_size, is_backfill_complete);
+                        }
+                        future::ready(keep_going.then_some(res))
                     })
                     .map_ok(|(doc, ts)| (ts, doc.id_with_table_id(), Some(doc)))
                     .boxed();
@@ -0,0 +1,138 @@
+//! Memory-bounded streaming compaction of search index segments.
+//!
+//! `download_previous_segments` normally pulls every input segment to local
+//! disk before a build runs, so the largest index a flusher host can
+//! compact is bounded by that host's local disk and memory. Borrowing the
+//! streaming-compaction approach from libsql's WAL compactor, this module
+//! folds a bounded window of segments together at a time: only the window's
+//! segments are downloaded and merged, and anything left over is handed
+//! back to the caller untouched. `SearchFlusher::build_merge_segment` calls
+//! this once per flusher iteration, so a merge candidate far bigger than
+//! `compaction_window_bytes` is compacted incrementally across iterations
+//! instead of all at once -- the same checkpoint-by-leftover-state pattern
+//! `build_multipart_segment_on_thread` already uses for `IncrementalComplete`
+//! backfills, just expressed as "segments not yet folded in" instead of a
+//! document cursor.
+
+use std::{
+    path::Path,
+    sync::Arc,
+};
+
+use common::runtime::Runtime;
+use storage::Storage;
+
+use crate::index_workers::index_meta::SearchIndex;
+
+/// Implemented by index types that can fold the contents of several
+/// existing segments into one. This is distinct from `build_disk_index`,
+/// which only ever builds a new segment from a stream of *table* documents
+/// and otherwise leaves any `previous_segments` passed to it untouched
+/// (aside from applying deletes) -- it has no way to combine one segment's
+/// contents into another. Index types opt into `LogMergePolicy`-driven
+/// merges by implementing this, analogous to how tantivy's `IndexWriter`
+/// merges a set of segment ids into one.
+pub trait SegmentMerge: SearchIndex {
+    fn merge_segments(
+        index_path: &Path,
+        segments: Vec<Self::Segment>,
+    ) -> impl std::future::Future<Output = anyhow::Result<Option<Self::NewSegment>>> + Send;
+}
+
+/// The result of compacting one window: the segment produced by folding the
+/// window together (`None` if the window turned out to contain nothing
+/// worth merging), and any input segments that weren't part of this window
+/// because including them would have exceeded `compaction_window_bytes`.
+/// Callers should treat `leftover` the same way `build_multipart_segment_on_thread`
+/// treats an incomplete backfill: keep it around and let the next flusher
+/// iteration make further progress on it.
+pub struct CompactionResult<Segment> {
+    pub merged: Option<Segment>,
+    pub leftover: Vec<Segment>,
+}
+
+/// Compact as large a prefix of `segments` as fits under
+/// `compaction_window_bytes` (estimated via `segment_size_bytes`) into a
+/// single new segment, downloading and merging only that prefix. The first
+/// two segments are always admitted, even when together they exceed the
+/// budget, so every call with two or more segments merges something. Unlike
+/// folding the whole candidate list in one call, this keeps peak local
+/// footprint bounded by the window rather than the candidate's total size,
+/// at the cost of needing to be called again (with the returned `leftover`
+/// folded back into the candidate list) to finish an oversized merge.
+pub async fn compact_window<RT, T>(
+    runtime: &RT,
+    storage: Arc<dyn Storage>,
+    index_path: &Path,
+    segments: Vec<T::Segment>,
+    segment_size_bytes: Vec<u64>,
+    compaction_window_bytes: u64,
+) -> anyhow::Result<CompactionResult<T::Segment>>
+where
+    RT: Runtime,
+    T: SegmentMerge,
+{
+    anyhow::ensure!(segments.len() == segment_size_bytes.len());
+
+    let mut pairs = segments.into_iter().zip(segment_size_bytes);
+    let mut window = vec![];
+    let mut window_size = 0u64;
+    for (segment, size) in pairs.by_ref() {
+        // Only enforce the budget once the window already has something to
+        // merge: a single oversized segment must still be admitted (and
+        // folded in alongside whatever comes next), or a candidate whose
+        // largest segment alone exceeds `compaction_window_bytes` would get
+        // stuck at `window.len() == 1` -- and therefore `merged: None` --
+        // forever.
+        if window.len() >= 2 && window_size + size > compaction_window_bytes {
+            // Put this segment back at the front of the remaining pairs by
+            // collecting it into `leftover` below along with everything
+            // after it.
+            let mut leftover = vec![segment];
+            leftover.extend(pairs.map(|(segment, _)| segment));
+            return finish_window(runtime, storage, index_path, window, leftover).await;
+        }
+        if window_size + size > compaction_window_bytes {
+            tracing::warn!(
+                "Segment ({size} bytes) takes the compaction window past \
+                 compaction_window_bytes ({compaction_window_bytes}); merging it anyway \
+                 to guarantee progress"
+            );
+        }
+        window_size += size;
+        window.push(segment);
+    }
+    finish_window(runtime, storage, index_path, window, vec![]).await
+}
+
+async fn finish_window<RT, T>(
+    runtime: &RT,
+    storage: Arc<dyn Storage>,
+    index_path: &Path,
+    window: Vec<T::Segment>,
+    leftover: Vec<T::Segment>,
+) -> anyhow::Result<CompactionResult<T::Segment>>
+where
+    RT: Runtime,
+    T: SegmentMerge,
+{
+    if window.len() < 2 {
+        // A window of zero or one segments has nothing to fold together;
+        // hand it all back as leftover so the merge policy can reconsider it
+        // once more segments accumulate, rather than churning a no-op merge.
+        let mut leftover = leftover;
+        leftover.splice(0..0, window);
+        return Ok(CompactionResult {
+            merged: None,
+            leftover,
+        });
+    }
+
+    let mut downloaded = T::download_previous_segments(storage.clone(), window).await?;
+    let merged = T::merge_segments(index_path, std::mem::take(&mut downloaded)).await?;
+    let merged = match merged {
+        Some(new_segment) => Some(T::upload_new_segment(runtime, storage, new_segment).await?),
+        None => None,
+    };
+    Ok(CompactionResult { merged, leftover })
+}
@@ -0,0 +1,224 @@
+//! Queryable task-status tracking for index builds.
+//!
+//! Before this module, callers of [`SearchFlusher`](super::search_flusher::SearchFlusher)
+//! only ever saw a build's final `IndexBuildResult`; there was no way to ask
+//! "what is the flusher doing right now" or "how did the last rebuild of
+//! this index go". This is modeled on MeiliSearch's task queue: every
+//! `IndexBuild` becomes a [`Task`] with a stable [`TaskUid`], a [`Status`],
+//! and, for in-flight `IncrementalComplete` builds, live progress.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+};
+
+use common::types::{
+    IndexId,
+    UnixTimestamp,
+};
+
+use crate::index_workers::{
+    index_meta::SegmentStatistics,
+    BuildReason,
+};
+
+pub type TaskUid = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Enqueued,
+    Processing,
+    Succeeded,
+    Failed,
+}
+
+/// Live progress for a task currently running an `IncrementalComplete`
+/// build, derived from the same cursor/completion bookkeeping
+/// `build_multipart_segment_on_thread` already tracks.
+#[derive(Debug, Clone, Default)]
+pub struct TaskProgress {
+    pub bytes_scanned: u64,
+    pub is_backfill_complete: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Task<Stats> {
+    pub uid: TaskUid,
+    pub index_id: IndexId,
+    pub index_name: String,
+    pub build_reason: BuildReason,
+    pub status: Status,
+    pub enqueued_at: UnixTimestamp,
+    pub started_at: Option<UnixTimestamp>,
+    pub finished_at: Option<UnixTimestamp>,
+    pub progress: TaskProgress,
+    pub error: Option<String>,
+    /// Set once the task succeeds; mirrors `IndexBuildResult::total_stats`.
+    pub final_stats: Option<Stats>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TaskQuery {
+    pub status: Option<Status>,
+    pub index_uid: Option<String>,
+    pub uid: Option<TaskUid>,
+    pub from: Option<TaskUid>,
+    pub limit: Option<usize>,
+}
+
+/// Durable storage for completed tasks, so task history survives a flusher
+/// restart. Implemented separately from [`TaskStore`] so the in-memory
+/// tracking used for live progress doesn't need to agree on a storage
+/// backend with whatever persists history (e.g. a system table written
+/// through the same model `IndexWorkerMetadataModel` already uses for
+/// per-index fast-forward bookkeeping).
+pub trait TaskHistory<Stats>: Send + Sync {
+    fn persist(&self, task: &Task<Stats>);
+    fn load(&self) -> Vec<Task<Stats>>;
+}
+
+/// An in-flight and historical task-status store for one `SearchFlusher`.
+/// Holds recent tasks in memory for fast, detailed progress queries, and
+/// forwards completed tasks to an optional [`TaskHistory`] backend so
+/// history isn't lost on restart.
+pub struct TaskStore<Stats> {
+    next_uid: AtomicU64,
+    tasks: Mutex<VecDeque<Task<Stats>>>,
+    max_in_memory: usize,
+    history: Option<Arc<dyn TaskHistory<Stats>>>,
+}
+
+impl<Stats: SegmentStatistics + Clone> TaskStore<Stats> {
+    pub fn new(max_in_memory: usize, history: Option<Arc<dyn TaskHistory<Stats>>>) -> Self {
+        let tasks = history
+            .as_ref()
+            .map(|history| history.load())
+            .unwrap_or_default();
+        let next_uid = tasks.iter().map(|task| task.uid).max().map_or(0, |uid| uid + 1);
+        Self {
+            next_uid: AtomicU64::new(next_uid),
+            tasks: Mutex::new(tasks.into()),
+            max_in_memory,
+            history,
+        }
+    }
+
+    pub fn enqueue(
+        &self,
+        index_id: IndexId,
+        index_name: String,
+        build_reason: BuildReason,
+        now: UnixTimestamp,
+    ) -> TaskUid {
+        let uid = self.next_uid.fetch_add(1, Ordering::SeqCst);
+        let task = Task {
+            uid,
+            index_id,
+            index_name,
+            build_reason,
+            status: Status::Enqueued,
+            enqueued_at: now,
+            started_at: None,
+            finished_at: None,
+            progress: TaskProgress::default(),
+            error: None,
+            final_stats: None,
+        };
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.push_back(task);
+        while tasks.len() > self.max_in_memory {
+            tasks.pop_front();
+        }
+        uid
+    }
+
+    pub fn mark_processing(&self, uid: TaskUid, now: UnixTimestamp) {
+        self.update(uid, |task| {
+            task.status = Status::Processing;
+            task.started_at = Some(now);
+        });
+    }
+
+    /// Update live progress for a task that's in the middle of an
+    /// `IncrementalComplete` build. Called from `build_multipart_segment_on_thread`
+    /// alongside its existing `new_cursor`/`is_backfill_complete` updates.
+    pub fn update_progress(&self, uid: TaskUid, bytes_scanned: u64, is_backfill_complete: bool) {
+        self.update(uid, |task| {
+            task.progress.bytes_scanned = bytes_scanned;
+            task.progress.is_backfill_complete = is_backfill_complete;
+        });
+    }
+
+    pub fn mark_succeeded(&self, uid: TaskUid, final_stats: Stats, now: UnixTimestamp) {
+        self.finish(uid, Status::Succeeded, now, |task| {
+            task.final_stats = Some(final_stats);
+        });
+    }
+
+    pub fn mark_failed(&self, uid: TaskUid, error: String, now: UnixTimestamp) {
+        self.finish(uid, Status::Failed, now, |task| {
+            task.error = Some(error);
+        });
+    }
+
+    fn finish(
+        &self,
+        uid: TaskUid,
+        status: Status,
+        now: UnixTimestamp,
+        set_result: impl FnOnce(&mut Task<Stats>),
+    ) {
+        let persisted = {
+            let mut tasks = self.tasks.lock().unwrap();
+            let task = tasks.iter_mut().find(|task| task.uid == uid);
+            if let Some(task) = task {
+                task.status = status;
+                task.finished_at = Some(now);
+                set_result(task);
+                Some(task.clone())
+            } else {
+                None
+            }
+        };
+        if let (Some(task), Some(history)) = (persisted, &self.history) {
+            history.persist(&task);
+        }
+    }
+
+    fn update(&self, uid: TaskUid, f: impl FnOnce(&mut Task<Stats>)) {
+        let mut tasks = self.tasks.lock().unwrap();
+        if let Some(task) = tasks.iter_mut().find(|task| task.uid == uid) {
+            f(task);
+        }
+    }
+
+    /// Query in-flight and historical tasks, most recently enqueued first.
+    pub fn query(&self, query: &TaskQuery) -> Vec<Task<Stats>>
+    where
+        Stats: Clone,
+    {
+        let tasks = self.tasks.lock().unwrap();
+        tasks
+            .iter()
+            .rev()
+            .filter(|task| query.status.is_none_or(|status| status == task.status))
+            .filter(|task| {
+                query
+                    .index_uid
+                    .as_ref()
+                    .is_none_or(|index_uid| *index_uid == task.index_name)
+            })
+            .filter(|task| query.uid.is_none_or(|uid| uid == task.uid))
+            .filter(|task| query.from.is_none_or(|from| task.uid <= from))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+}